@@ -0,0 +1,78 @@
+//! Developer-facing entry point for repo setup tasks (git hooks, editor
+//! config) that used to be split across npm scripts and `build.rs`.
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use contracts::{hooks, vscode};
+
+#[derive(Parser)]
+#[command(name = "xtask", about = "Repository setup and maintenance tasks")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Install a managed resource (e.g. git hooks)
+    Setup {
+        #[command(subcommand)]
+        target: SetupTarget,
+    },
+    /// Remove a previously installed resource
+    Remove {
+        #[command(subcommand)]
+        target: RemoveTarget,
+    },
+    /// Run the commands configured for a git hook in `hooks.toml`.
+    ///
+    /// Invoked by the hook shims `xtask setup git-hook` installs; not meant
+    /// to be run by hand.
+    RunHook {
+        /// Git hook name, e.g. `pre-commit`
+        hook: String,
+        /// Arguments git passed to the hook
+        args: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SetupTarget {
+    /// Install this repo's managed git hooks via core.hooksPath
+    GitHook {
+        /// Overwrite an existing hooksPath or hook files not authored by this tool
+        #[arg(long)]
+        force: bool,
+    },
+    /// Write .vscode/settings.json and tasks.json for this repo
+    Vscode {
+        /// Overwrite a file even if it's been modified from a known default
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum RemoveTarget {
+    /// Remove this repo's managed git hooks and unset core.hooksPath
+    GitHook,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Setup { target: SetupTarget::GitHook { force } } => hooks::install_hook(force),
+        Command::Setup { target: SetupTarget::Vscode { force } } => {
+            contracts::repo_root().and_then(|repo_root| vscode::install_vscode_config(&repo_root, force))
+        }
+        Command::Remove { target: RemoveTarget::GitHook } => hooks::remove_hook(),
+        Command::RunHook { hook, args } => hooks::run_hook(&hook, &args),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,15 @@
+//! Detect whether we're running on a CI build agent.
+//!
+//! Mirrors the guard rusty-hook applies via `ci_info` around its build-time
+//! hook installation: hook setup/teardown should never touch a CI runner's
+//! git config.
+
+use std::env;
+
+/// Environment variables set by common CI providers.
+const CI_ENV_VARS: &[&str] = &["CI", "GITHUB_ACTIONS", "GITLAB_CI", "BUILD_ID", "TF_BUILD", "JENKINS_URL"];
+
+/// Whether the current process looks like it's running under CI.
+pub fn is_ci() -> bool {
+    CI_ENV_VARS.iter().any(|var| env::var_os(var).is_some_and(|value| !value.is_empty()))
+}
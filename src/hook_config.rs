@@ -0,0 +1,45 @@
+//! Parsing for `hooks.toml`, the declarative replacement for husky's shell
+//! shims: a map of git hook name to an ordered list of shell commands to run
+//! for that hook.
+//!
+//! ```toml
+//! pre-commit = ["cargo fmt --check", "forge test"]
+//! commit-msg = ["cargo run --quiet --bin commitlint"]
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Name of the config file, relative to the repo root.
+pub const CONFIG_FILE: &str = "hooks.toml";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HookConfig(BTreeMap<String, Vec<String>>);
+
+impl HookConfig {
+    /// Load `hooks.toml` from the repo root. A missing file is treated as an
+    /// empty config rather than an error, since not every clone opts in.
+    pub fn load(repo_root: &Path) -> io::Result<Self> {
+        let path = repo_root.join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("invalid {CONFIG_FILE}: {err}")))
+    }
+
+    /// Git hook names with at least one configured command.
+    pub fn hook_names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter(|(_, commands)| !commands.is_empty()).map(|(name, _)| name.as_str())
+    }
+
+    /// The ordered commands configured for `hook`, empty if none.
+    pub fn commands_for(&self, hook: &str) -> &[String] {
+        self.0.get(hook).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
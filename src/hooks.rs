@@ -0,0 +1,190 @@
+//! Git hook management for this repo.
+//!
+//! This replaces the old husky/npm setup: instead of a JS install script and
+//! a Rust build script that only ever tears things down, `install_hook` and
+//! `remove_hook` are symmetric operations on the same `core.hooksPath`
+//! managed hooks directory, mirroring the approach `clippy_dev`'s
+//! `setup::git_hook` module uses for rust-clippy.
+//!
+//! Each managed hook is a thin POSIX shell shim that re-invokes this binary
+//! via `run_hook`, which looks up the hook's commands in `hooks.toml`
+//! ([`crate::hook_config`]) and runs them in order.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+
+use crate::ci;
+use crate::hook_config::HookConfig;
+use crate::repo_root;
+
+/// Directory (relative to the repo root) where this tool's hook scripts live.
+const HOOKS_DIR: &str = ".git-hooks";
+
+/// Marker line written into every hook script we author, so `remove_hook`
+/// can tell our files apart from hooks a developer placed there by hand.
+const MANAGED_MARKER: &str = "# managed-by: contracts-hooks";
+
+/// Install the managed hooks directory and point `core.hooksPath` at it.
+///
+/// Writes one shim per hook configured in `hooks.toml`. Fails if a different
+/// `core.hooksPath` is already configured, or if one of the managed hook
+/// files already exists and wasn't authored by this tool, unless `force` is
+/// set.
+pub fn install_hook(force: bool) -> io::Result<()> {
+    if ci::is_ci() {
+        println!("skipping hook setup in CI");
+        return Ok(());
+    }
+
+    let repo_root = repo_root()?;
+    let hooks_dir = repo_root.join(HOOKS_DIR);
+    let config = HookConfig::load(&repo_root)?;
+
+    if let Some(existing) = configured_hooks_path(&repo_root)? {
+        let existing_path = repo_root.join(&existing);
+        if existing_path != hooks_dir && !force {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "core.hooksPath is already set to '{existing}'; rerun with --force to overwrite it"
+                ),
+            ));
+        }
+    }
+
+    for name in config.hook_names() {
+        let path = hooks_dir.join(name);
+        if path.exists() && !force && !is_managed_file(&path)? {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "{} already exists and was not written by this tool; rerun with --force to overwrite it",
+                    path.display()
+                ),
+            ));
+        }
+    }
+
+    fs::create_dir_all(&hooks_dir)?;
+    for name in config.hook_names() {
+        write_hook_script(&hooks_dir.join(name), name)?;
+    }
+    set_hooks_path(&repo_root, HOOKS_DIR)
+}
+
+/// Remove the hook files this tool authored and unset `core.hooksPath`.
+///
+/// Hook files that weren't written by this tool (no `MANAGED_MARKER`) are
+/// left untouched.
+pub fn remove_hook() -> io::Result<()> {
+    if ci::is_ci() {
+        println!("skipping hook removal in CI");
+        return Ok(());
+    }
+
+    let repo_root = repo_root()?;
+    let hooks_dir = repo_root.join(HOOKS_DIR);
+
+    if hooks_dir.is_dir() {
+        for entry in fs::read_dir(&hooks_dir)? {
+            let path = entry?.path();
+            if path.is_file() && is_managed_file(&path)? {
+                fs::remove_file(&path)?;
+            }
+        }
+        if fs::read_dir(&hooks_dir)?.next().is_none() {
+            fs::remove_dir(&hooks_dir)?;
+        }
+    }
+
+    unset_hooks_path()
+}
+
+/// Run the commands configured for `hook` in `hooks.toml`, in order,
+/// forwarding `args` (the arguments git passed to the hook) to each one.
+/// Aborts on the first command that exits non-zero.
+pub fn run_hook(hook: &str, args: &[String]) -> io::Result<()> {
+    let repo_root = repo_root()?;
+    let config = HookConfig::load(&repo_root)?;
+
+    for command in config.commands_for(hook) {
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(format!("{command} \"$@\""))
+            .arg(command.as_str())
+            .args(args)
+            .current_dir(&repo_root)
+            .status()?;
+        if !status.success() {
+            return Err(io::Error::other(format!("`{hook}` hook command failed: {command}")));
+        }
+    }
+    Ok(())
+}
+
+fn configured_hooks_path(repo_root: &Path) -> io::Result<Option<String>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["config", "--get", "core.hooksPath"])
+        .output()?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(if value.is_empty() { None } else { Some(value) })
+}
+
+fn set_hooks_path(repo_root: &Path, hooks_dir: &str) -> io::Result<()> {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["config", "core.hooksPath", hooks_dir])
+        .status()?;
+    if !status.success() {
+        return Err(io::Error::other("git config core.hooksPath failed"));
+    }
+    Ok(())
+}
+
+fn unset_hooks_path() -> io::Result<()> {
+    // Exits non-zero when the key was already absent, which is fine here.
+    let _ = Command::new("git").args(["config", "--unset", "core.hooksPath"]).status()?;
+    Ok(())
+}
+
+fn is_managed_file(path: &Path) -> io::Result<bool> {
+    Ok(fs::read_to_string(path)?.contains(MANAGED_MARKER))
+}
+
+fn write_hook_script(path: &Path, hook_name: &str) -> io::Result<()> {
+    let mut file = fs::File::create(path)?;
+    writeln!(file, "#!/bin/sh")?;
+    writeln!(file, "{MANAGED_MARKER}")?;
+    // Resolve the repo root from this script's own location (one level up
+    // from the managed hooks directory) rather than relying on $PWD, so the
+    // shim works regardless of where git invokes it from, and quote every
+    // expansion so paths containing spaces survive.
+    writeln!(file, "repo_root=\"$(cd \"$(dirname \"$0\")/..\" && pwd)\"")?;
+    writeln!(
+        file,
+        "exec cargo run --quiet --manifest-path \"$repo_root/Cargo.toml\" --bin xtask -- run-hook {hook_name} \"$@\""
+    )?;
+    drop(file);
+    make_executable(path)
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = fs::metadata(path)?.permissions();
+    perms.set_mode(perms.mode() | 0o111);
+    fs::set_permissions(path, perms)
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+
+pub mod ci;
+pub mod hook_config;
+pub mod hooks;
+pub mod vscode;
+
+/// Root of the git repository the current process is running in.
+pub fn repo_root() -> io::Result<PathBuf> {
+    let output = Command::new("git").args(["rev-parse", "--show-toplevel"]).output()?;
+    if !output.status.success() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "not inside a git repository"));
+    }
+    Ok(PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()))
+}
@@ -0,0 +1,75 @@
+//! `xtask setup vscode`: generate `.vscode/settings.json` and `tasks.json`,
+//! without clobbering a developer's hand-edited copy.
+//!
+//! Every template we've ever shipped has its SHA-256 hash recorded below.
+//! On write, we hash the file currently on disk: a match against a known
+//! historical hash means it's an unmodified old default and safe to
+//! overwrite; no match means a developer edited it, and we refuse unless
+//! `--force` is passed. Whenever a template changes, append its new hash to
+//! the relevant list below so the previous default stays recognized too.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+struct ManagedFile {
+    relative_path: &'static str,
+    template: &'static str,
+    known_hashes: &'static [&'static str],
+}
+
+const SETTINGS: ManagedFile = ManagedFile {
+    relative_path: ".vscode/settings.json",
+    template: include_str!("../templates/vscode/settings.json"),
+    known_hashes: &["d84a10fa8400ac154e4f3dba2e9f558d8c9e1e5a8d785f80c233c7d9f74d07cc"],
+};
+
+const TASKS: ManagedFile = ManagedFile {
+    relative_path: ".vscode/tasks.json",
+    template: include_str!("../templates/vscode/tasks.json"),
+    known_hashes: &["56fa0d35ff714f41968d4f54d4501fde8a891f0306baa6425f9e2c12125a8a74"],
+};
+
+const MANAGED_FILES: &[&ManagedFile] = &[&SETTINGS, &TASKS];
+
+/// Write this repo's `.vscode` config, skipping any file that's been
+/// modified from a default we recognize unless `force` is set.
+pub fn install_vscode_config(repo_root: &Path, force: bool) -> io::Result<()> {
+    if crate::ci::is_ci() {
+        println!("skipping vscode config setup in CI");
+        return Ok(());
+    }
+
+    for file in MANAGED_FILES {
+        write_if_safe(repo_root, file, force)?;
+    }
+    Ok(())
+}
+
+fn write_if_safe(repo_root: &Path, file: &ManagedFile, force: bool) -> io::Result<()> {
+    let path = repo_root.join(file.relative_path);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if path.exists() {
+        let on_disk = fs::read_to_string(&path)?;
+        let is_known_default = file.known_hashes.contains(&hash(&on_disk).as_str());
+        if !is_known_default && !force {
+            eprintln!(
+                "warning: {} doesn't match a known default; skipping (diff it against the template and rerun with --force to overwrite)",
+                path.display()
+            );
+            return Ok(());
+        }
+    }
+
+    fs::write(&path, file.template)
+}
+
+fn hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes()).iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,98 @@
+//! Installs the managed git hooks into a scratch repo whose path contains a
+//! space, then runs the resulting shim under both `dash` and `bash` to
+//! confirm the quoting in the generated scripts forwards git's arguments
+//! intact on every POSIX shell, not just the one a contributor happens to
+//! have as `/bin/sh`.
+//!
+//! The real shim execs `cargo run ... --bin xtask -- run-hook ...`, which
+//! this test can't rely on (no real build of this crate is guaranteed to be
+//! available). Instead it puts a fake `cargo` on `PATH` that just records
+//! the arguments it was invoked with, so the assertion is about whether the
+//! shell quoting preserved them, not about xtask actually running.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::process::Command;
+
+struct RestoreCwd(OsString);
+
+impl Drop for RestoreCwd {
+    fn drop(&mut self) {
+        let _ = env::set_current_dir(&self.0);
+    }
+}
+
+fn shell_available(shell: &str) -> bool {
+    Command::new(shell).arg("-c").arg(":").status().map(|status| status.success()).unwrap_or(false)
+}
+
+fn write_executable(path: &PathBuf, contents: &str) {
+    fs::write(path, contents).expect("write script");
+    let mut perms = fs::metadata(path).unwrap().permissions();
+    perms.set_mode(perms.mode() | 0o111);
+    fs::set_permissions(path, perms).unwrap();
+}
+
+#[test]
+fn hook_shims_forward_quoted_arguments_under_dash_and_bash() {
+    let scratch_root = env::temp_dir().join(format!("contracts-hook-shim-test-{}", std::process::id()));
+    let repo_dir = scratch_root.join("my repo");
+    fs::create_dir_all(&repo_dir).expect("create scratch repo dir");
+    assert!(
+        Command::new("git").args(["init", "--quiet"]).current_dir(&repo_dir).status().unwrap().success(),
+        "git init failed"
+    );
+    fs::write(repo_dir.join("hooks.toml"), "commit-msg = [\"cat\"]\n").expect("write hooks.toml");
+
+    let previous_cwd = env::current_dir().expect("current dir");
+    env::set_current_dir(&repo_dir).expect("enter scratch repo");
+    let _restore = RestoreCwd(previous_cwd.into_os_string());
+
+    contracts::hooks::install_hook(true).expect("install_hook should succeed in a fresh scratch repo");
+
+    let shim = repo_dir.join(".git-hooks").join("commit-msg");
+    assert!(shim.is_file(), "commit-msg shim was not written");
+
+    // Fake `cargo` that just echoes the argv it received, one per line, so
+    // the test can check that the commit-message path (which contains a
+    // space) survived the shim's `"$@"` forwarding as a single argument.
+    let fake_bin_dir = scratch_root.join("fake-bin");
+    fs::create_dir_all(&fake_bin_dir).expect("create fake bin dir");
+    write_executable(
+        &fake_bin_dir.join("cargo"),
+        "#!/bin/sh\nfor arg in \"$@\"; do printf '%s\\n' \"$arg\"; done\n",
+    );
+    let path_with_fake_cargo_first = format!("{}:{}", fake_bin_dir.display(), env::var("PATH").unwrap_or_default());
+
+    let commit_msg_path = repo_dir.join("COMMIT_EDITMSG");
+    fs::write(&commit_msg_path, "test commit\n").expect("write commit message file");
+
+    let mut ran_any = false;
+    for shell in ["dash", "bash"] {
+        if !shell_available(shell) {
+            continue;
+        }
+        ran_any = true;
+        let output = Command::new(shell)
+            .arg(&shim)
+            .arg(&commit_msg_path)
+            .current_dir(&repo_dir)
+            .env("PATH", &path_with_fake_cargo_first)
+            .output()
+            .unwrap_or_else(|err| panic!("failed to run shim under {shell}: {err}"));
+        assert!(output.status.success(), "commit-msg shim failed under {shell}: {output:?}");
+
+        let forwarded_args: Vec<&str> = std::str::from_utf8(&output.stdout).unwrap().lines().collect();
+        assert_eq!(
+            forwarded_args.last().copied(),
+            Some(commit_msg_path.to_str().unwrap()),
+            "commit-msg path with a space wasn't forwarded intact under {shell}: {forwarded_args:?}"
+        );
+    }
+    assert!(ran_any, "neither dash nor bash is available to exercise this test");
+
+    let _ = fs::remove_dir_all(&scratch_root);
+}
@@ -0,0 +1,125 @@
+//! Covers the force/overwrite logic in `hooks::install_hook` that's the
+//! actual point of the "symmetric setup/remove with force-override" and
+//! "skip hook files not authored by this tool" behavior: bailing on a
+//! conflicting `core.hooksPath` or an unmanaged hook file unless `--force`
+//! is passed, and proceeding when it is.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+// `install_hook` always operates on the process's current directory, so
+// these tests must chdir into a scratch repo. Since tests in this binary
+// can run on different threads of the same process, serialize anything
+// that touches the current directory through this lock.
+static CWD_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+
+fn cwd_lock() -> MutexGuard<'static, ()> {
+    CWD_LOCK.get_or_init(|| Mutex::new(())).lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+struct RestoreCwd {
+    previous: OsString,
+    // Held for the lifetime of the guard to serialize tests that chdir.
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl Drop for RestoreCwd {
+    fn drop(&mut self) {
+        let _ = env::set_current_dir(&self.previous);
+    }
+}
+
+fn scratch_repo(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("contracts-hooks-install-test-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("create scratch repo dir");
+    assert!(
+        Command::new("git").args(["init", "--quiet"]).current_dir(&dir).status().unwrap().success(),
+        "git init failed"
+    );
+    dir
+}
+
+fn enter(dir: &PathBuf) -> RestoreCwd {
+    let guard = cwd_lock();
+    let previous = env::current_dir().expect("current dir");
+    env::set_current_dir(dir).expect("enter scratch repo");
+    RestoreCwd { previous: previous.into_os_string(), _guard: guard }
+}
+
+#[test]
+fn bails_on_conflicting_hooks_path_without_force() {
+    let repo = scratch_repo("conflict");
+    let _restore = enter(&repo);
+
+    // The exact "fresh clone, husky never installed" scenario: core.hooksPath
+    // points somewhere that doesn't exist on disk yet.
+    assert!(Command::new("git")
+        .args(["config", "core.hooksPath", ".husky"])
+        .current_dir(&repo)
+        .status()
+        .unwrap()
+        .success());
+
+    let result = contracts::hooks::install_hook(false);
+    assert!(result.is_err(), "install_hook should bail on a conflicting core.hooksPath");
+
+    let configured = Command::new("git")
+        .args(["config", "--get", "core.hooksPath"])
+        .current_dir(&repo)
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&configured.stdout).trim(),
+        ".husky",
+        "core.hooksPath must not be touched when install_hook bails"
+    );
+
+    contracts::hooks::install_hook(true).expect("install_hook should succeed with --force");
+    let configured = Command::new("git")
+        .args(["config", "--get", "core.hooksPath"])
+        .current_dir(&repo)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&configured.stdout).trim(), ".git-hooks");
+
+    let _ = fs::remove_dir_all(&repo);
+}
+
+#[test]
+fn rerunning_install_over_its_own_hooks_path_does_not_require_force() {
+    let repo = scratch_repo("rerun");
+    let _restore = enter(&repo);
+
+    contracts::hooks::install_hook(false).expect("first install should succeed in a fresh repo");
+    contracts::hooks::install_hook(false)
+        .expect("reinstalling over our own core.hooksPath should not require --force");
+
+    let _ = fs::remove_dir_all(&repo);
+}
+
+#[test]
+fn bails_on_unmanaged_hook_file_without_force() {
+    let repo = scratch_repo("unmanaged-file");
+    let _restore = enter(&repo);
+
+    fs::write(repo.join("hooks.toml"), "pre-commit = [\"true\"]\n").expect("write hooks.toml");
+    let hooks_dir = repo.join(".git-hooks");
+    fs::create_dir_all(&hooks_dir).expect("create hooks dir");
+    fs::write(hooks_dir.join("pre-commit"), "#!/bin/sh\necho hand-written\n").expect("write unmanaged hook file");
+
+    let result = contracts::hooks::install_hook(false);
+    assert!(result.is_err(), "install_hook should bail on a hook file it didn't author");
+    let contents = fs::read_to_string(hooks_dir.join("pre-commit")).unwrap();
+    assert!(contents.contains("hand-written"), "unmanaged hook file must be left untouched when install_hook bails");
+
+    contracts::hooks::install_hook(true).expect("install_hook should succeed with --force");
+    let contents = fs::read_to_string(hooks_dir.join("pre-commit")).unwrap();
+    assert!(contents.contains("managed-by: contracts-hooks"), "--force should overwrite the unmanaged file");
+
+    let _ = fs::remove_dir_all(&repo);
+}
@@ -0,0 +1,57 @@
+//! Covers the hash-gated overwrite logic in `vscode::install_vscode_config`:
+//! an unmodified known default is refreshed silently, but a file that's been
+//! hand-edited is left alone unless `--force` is passed.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("contracts-vscode-setup-test-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+#[test]
+fn rewrites_an_unmodified_known_default_without_force() {
+    let repo = scratch_dir("known-default");
+
+    contracts::vscode::install_vscode_config(&repo, true).expect("initial install with --force should succeed");
+    let settings_path = repo.join(".vscode").join("settings.json");
+    let original = fs::read_to_string(&settings_path).unwrap();
+
+    // Re-running without --force over an untouched, known-default file
+    // should succeed and leave the template content in place.
+    contracts::vscode::install_vscode_config(&repo, false)
+        .expect("reinstalling over an unmodified known default should not require --force");
+    assert_eq!(fs::read_to_string(&settings_path).unwrap(), original);
+
+    let _ = fs::remove_dir_all(&repo);
+}
+
+#[test]
+fn refuses_to_overwrite_a_modified_file_without_force() {
+    let repo = scratch_dir("modified");
+
+    contracts::vscode::install_vscode_config(&repo, true).expect("initial install with --force should succeed");
+    let settings_path = repo.join(".vscode").join("settings.json");
+    fs::write(&settings_path, "// hand-edited by a developer\n{}\n").expect("simulate a local edit");
+
+    contracts::vscode::install_vscode_config(&repo, false)
+        .expect("install_vscode_config itself doesn't error; it just skips the file");
+    assert_eq!(
+        fs::read_to_string(&settings_path).unwrap(),
+        "// hand-edited by a developer\n{}\n",
+        "a modified settings.json must not be overwritten without --force"
+    );
+
+    contracts::vscode::install_vscode_config(&repo, true).expect("install with --force should succeed");
+    assert_ne!(
+        fs::read_to_string(&settings_path).unwrap(),
+        "// hand-edited by a developer\n{}\n",
+        "--force should overwrite a modified settings.json"
+    );
+
+    let _ = fs::remove_dir_all(&repo);
+}